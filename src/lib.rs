@@ -22,6 +22,15 @@ pub struct TakeLastXBytes(pub usize);
 /// let address = hex::decode("d8b934580fcE35a11B58C6D73aDeE468a2833fa8").unwrap();
 /// let address: [u8; 20] = address.try_into().unwrap();
 /// SolidityDataType::Address(Address::from(address));
+/// // Int24, holding -2 as its two's-complement bit pattern
+/// SolidityDataType::Int(U256::MAX - U256::from(1), TakeLastXBytes(24));
+/// // uint256[], each element is padded to 32 bytes
+/// SolidityDataType::Array(vec![
+///     SolidityDataType::Number(U256::from(1)),
+///     SolidityDataType::Number(U256::from(2)),
+/// ]);
+/// // bytes4 selector
+/// SolidityDataType::FixedBytes(&[0xde, 0xad, 0xbe, 0xef], 4);
 /// ```
 pub enum SolidityDataType<'a> {
     String(&'a str),
@@ -30,14 +39,78 @@ pub enum SolidityDataType<'a> {
     Bool(bool),
     Number(U256),
     NumberWithShift(U256, TakeLastXBytes),
+    Int(U256, TakeLastXBytes),
+    Array(Vec<SolidityDataType<'a>>),
+    /// A fixed-size `bytesN` value: the slice and its declared width `N`. Unlike
+    /// `Bytes`, which emits the slice verbatim, `FixedBytes` validates the slice
+    /// is exactly `N` bytes and right-pads to 32 bytes in an `Array`/struct
+    /// context, matching Solidity's `bytesN` value-type semantics.
+    FixedBytes(&'a [u8], usize),
+}
+
+/// The fixed-width schema of a single value in a packed byte stream, for use
+/// with [`abi::decode_packed`]. Unlike [`SolidityDataType`] this describes a
+/// *type*, not a value, so the decoder knows how many bytes to consume.
+///
+/// `String`/`Bytes` are dynamic (no length prefix in packed encoding) and are
+/// therefore only recoverable in the terminal position of a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolidityType {
+    Address,
+    Uint(usize),
+    Int(usize),
+    Bool,
+    FixedBytes(usize),
+    String,
+    Bytes,
+}
+
+/// A value decoded by [`abi::decode_packed`] according to a [`SolidityType`]
+/// schema entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedValue {
+    Address(Address),
+    Uint(U256),
+    /// The two's-complement bit pattern of a signed integer (same convention
+    /// as [`SolidityDataType::Int`]).
+    Int(U256),
+    Bool(bool),
+    FixedBytes(Vec<u8>),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+/// An error produced while decoding a packed byte stream against a
+/// [`SolidityType`] schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A dynamic `String`/`Bytes` type appeared before the last schema entry;
+    /// its length can't be recovered from packed encoding alone.
+    UnrecoverableDynamicType(usize),
+    /// Fewer bytes remained than the schema entry at `index` requires.
+    UnexpectedEndOfInput { index: usize, needed: usize, remaining: usize },
+    /// Bytes were left over after every schema entry was decoded.
+    TrailingBytes(usize),
+    /// A terminal `String` entry's bytes were not valid UTF-8.
+    InvalidUtf8,
+}
+
+/// An error produced while packing a [`SolidityDataType`] into bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A [`SolidityDataType::FixedBytes`] value's slice length didn't match
+    /// its declared width.
+    FixedBytesLengthMismatch { declared: usize, actual: usize },
 }
 
 pub mod abi {
 
     use crate::SolidityDataType;
+    use ethabi::ethereum_types::U256;
+    use tiny_keccak::{Hasher, Keccak};
 
     /// Pack a single `SolidityDataType` into bytes
-    fn pack<'a>(data_type: &'a SolidityDataType) -> Vec<u8> {
+    pub(crate) fn pack(data_type: &SolidityDataType) -> Result<Vec<u8>, crate::EncodeError> {
         let mut res = Vec::new();
         match data_type {
             SolidityDataType::String(s) => {
@@ -63,18 +136,98 @@ pub mod abi {
                 }
             }
             SolidityDataType::NumberWithShift(n, to_take) => {
-                let local_res = n.0.iter().rev().fold(vec![], |mut acc, i| {
-                    let bytes = i.to_be_bytes();
-                    acc.extend(bytes);
-                    acc
-                });
-
-                let to_skip = local_res.len() - (to_take.0 / 8);
-                let local_res = local_res.into_iter().skip(to_skip).collect::<Vec<u8>>();
-                res.extend(local_res);
+                res.extend(take_last_x_bytes(n, to_take));
+            }
+            SolidityDataType::Int(n, to_take) => {
+                // `n` already holds the two's-complement bit pattern of the signed
+                // value (e.g. `U256::MAX` for `-1`), so truncating to the last
+                // `bits / 8` bytes both packs the magnitude and sign-extends
+                // correctly - no special casing is needed for negative numbers.
+                res.extend(take_last_x_bytes(n, to_take));
+            }
+            SolidityDataType::Array(items) => {
+                for item in items {
+                    res.extend(pack_array_element(item)?);
+                }
+            }
+            SolidityDataType::FixedBytes(b, width) => {
+                if b.len() != *width {
+                    return Err(crate::EncodeError::FixedBytesLengthMismatch {
+                        declared: *width,
+                        actual: b.len(),
+                    });
+                }
+                res.extend(*b);
             }
         };
-        return res;
+        Ok(res)
+    }
+
+    /// Pack a single element of an `Array`, padded to 32 bytes as real
+    /// `abi.encodePacked` does for array/struct members (as opposed to the tight
+    /// packing used for top-level items). `bytesN`/`string` are right-padded,
+    /// everything else (numbers, bools, addresses) is zero-padded on the left.
+    fn pack_array_element(item: &SolidityDataType) -> Result<Vec<u8>, crate::EncodeError> {
+        let packed = pack(item)?;
+        Ok(match item {
+            SolidityDataType::String(_)
+            | SolidityDataType::Bytes(_)
+            | SolidityDataType::FixedBytes(_, _) => pad_right_32(packed),
+            SolidityDataType::Int(_, _) => pad_left_32_sign_extend(packed),
+            _ => pad_left_32(packed),
+        })
+    }
+
+    /// Zero-pad `bytes` on the left so it is exactly 32 bytes long. Left as-is if
+    /// already 32 bytes or longer (e.g. a nested `Array`).
+    pub(crate) fn pad_left_32(bytes: Vec<u8>) -> Vec<u8> {
+        if bytes.len() >= 32 {
+            return bytes;
+        }
+        let mut res = vec![0u8; 32 - bytes.len()];
+        res.extend(bytes);
+        res
+    }
+
+    /// Sign-extend `bytes` on the left so it is exactly 32 bytes long, filling
+    /// with `0xff` rather than `0x00` when the leading byte's high bit is set
+    /// (a negative `SolidityDataType::Int` array/struct element). Left as-is if
+    /// already 32 bytes or longer.
+    pub(crate) fn pad_left_32_sign_extend(bytes: Vec<u8>) -> Vec<u8> {
+        if bytes.len() >= 32 {
+            return bytes;
+        }
+        let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+            0xffu8
+        } else {
+            0u8
+        };
+        let mut res = vec![fill; 32 - bytes.len()];
+        res.extend(bytes);
+        res
+    }
+
+    /// Zero-pad `bytes` on the right so it is exactly 32 bytes long. Left as-is if
+    /// already 32 bytes or longer.
+    pub(crate) fn pad_right_32(mut bytes: Vec<u8>) -> Vec<u8> {
+        if bytes.len() >= 32 {
+            return bytes;
+        }
+        bytes.resize(32, 0);
+        bytes
+    }
+
+    /// Serialize `n` big-endian across all 32 bytes, then keep only the last
+    /// `to_take.0 / 8` bytes.
+    fn take_last_x_bytes(n: &U256, to_take: &crate::TakeLastXBytes) -> Vec<u8> {
+        let local_res = n.0.iter().rev().fold(vec![], |mut acc, i| {
+            let bytes = i.to_be_bytes();
+            acc.extend(bytes);
+            acc
+        });
+
+        let to_skip = local_res.len() - (to_take.0 / 8);
+        local_res.into_iter().skip(to_skip).collect::<Vec<u8>>()
     }
 
 
@@ -95,20 +248,455 @@ pub mod abi {
     ///     SolidityDataType::Address(Address::from(address)),
     ///     SolidityDataType::Number(U256::from(1)),
     /// ];
-    /// let (_bytes, hash) = abi::encode_packed(&input);
+    /// let (_bytes, hash) = abi::encode_packed(&input).unwrap();
     /// let hash = format!("0x{:}", hash);
     /// let expected = "0x000efe0000000000000000000000000000000000000000000000000000000000000fa1746869732d69732d612d73616d706c652d737472696e67d8b934580fce35a11b58c6d73adee468a2833fa80000000000000000000000000000000000000000000000000000000000000001";
     /// assert_eq!(hash, expected);
     /// ```
-    pub fn encode_packed(items: &[SolidityDataType]) -> (Vec<u8>, String) {
-        let res = items.iter().fold(Vec::new(), |mut acc, i| {
-            let pack = pack(i);
-            acc.push(pack);
-            acc
-        });
+    pub fn encode_packed(items: &[SolidityDataType]) -> Result<(Vec<u8>, String), crate::EncodeError> {
+        let mut res = Vec::new();
+        for i in items {
+            res.push(pack(i)?);
+        }
         let res = res.join(&[][..]);
         let hexed = hex::encode(&res);
-        (res, hexed)
+        Ok((res, hexed))
+    }
+
+    /// Pack `items` the same way as [`encode_packed`], but also run the packed
+    /// bytes through `keccak256`. This is the hash Solidity's `abi.encodePacked`
+    /// is almost always fed into (signature pre-images, Merkle leaves, `CREATE2`
+    /// salts, ...), so callers no longer need to pull in a hashing crate of their
+    /// own just to reproduce it.
+    /// ```rust
+    /// use eth_encode_packed::SolidityDataType;
+    /// use eth_encode_packed::abi;
+    ///
+    /// let input = vec![SolidityDataType::String("hello world")];
+    /// let (_bytes, hash) = abi::encode_packed_keccak(&input).unwrap();
+    /// let hash = format!("0x{}", eth_encode_packed::hex::encode(hash));
+    /// let expected = "0x47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad";
+    /// assert_eq!(hash, expected);
+    /// ```
+    pub fn encode_packed_keccak(
+        items: &[SolidityDataType],
+    ) -> Result<(Vec<u8>, [u8; 32]), crate::EncodeError> {
+        let (res, _hexed) = encode_packed(items)?;
+        let mut hasher = Keccak::v256();
+        hasher.update(&res);
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        Ok((res, hash))
+    }
+
+    /// The width in bytes of a single fixed-width [`crate::SolidityType`]
+    /// schema entry. Returns `None` for the dynamic `String`/`Bytes` types.
+    fn fixed_width(ty: &crate::SolidityType) -> Option<usize> {
+        match ty {
+            crate::SolidityType::Address => Some(20),
+            crate::SolidityType::Uint(bits) => Some(bits / 8),
+            crate::SolidityType::Int(bits) => Some(bits / 8),
+            crate::SolidityType::Bool => Some(1),
+            crate::SolidityType::FixedBytes(n) => Some(*n),
+            crate::SolidityType::String | crate::SolidityType::Bytes => None,
+        }
+    }
+
+    fn decode_fixed(ty: &crate::SolidityType, chunk: &[u8]) -> crate::DecodedValue {
+        match ty {
+            crate::SolidityType::Address => {
+                crate::DecodedValue::Address(crate::ethabi::ethereum_types::Address::from_slice(
+                    chunk,
+                ))
+            }
+            crate::SolidityType::Uint(_) => {
+                crate::DecodedValue::Uint(U256::from_big_endian(chunk))
+            }
+            crate::SolidityType::Int(_) => {
+                // Sign-extend `chunk` to 32 bytes before reading it as a `U256`,
+                // so a negative value's high bytes come back as `0xff`, not
+                // `0x00` (the two's-complement convention
+                // [`crate::DecodedValue::Int`] documents).
+                let mut buf = if chunk[0] & 0x80 != 0 {
+                    [0xffu8; 32]
+                } else {
+                    [0u8; 32]
+                };
+                buf[32 - chunk.len()..].copy_from_slice(chunk);
+                crate::DecodedValue::Int(U256::from_big_endian(&buf))
+            }
+            crate::SolidityType::Bool => crate::DecodedValue::Bool(chunk[0] != 0),
+            crate::SolidityType::FixedBytes(_) => crate::DecodedValue::FixedBytes(chunk.to_vec()),
+            crate::SolidityType::String | crate::SolidityType::Bytes => unreachable!(),
+        }
+    }
+
+    /// Decode a packed byte stream back into values, given the ordered,
+    /// fixed-width `schema` that produced it. Packed encoding carries no length
+    /// prefixes, so a dynamic `SolidityType::String`/`Bytes` entry is only
+    /// recoverable as the last entry in `schema` (it consumes all remaining
+    /// bytes); one in any earlier position is rejected.
+    /// ```rust
+    /// use eth_encode_packed::abi;
+    /// use eth_encode_packed::{DecodedValue, SolidityDataType, SolidityType};
+    /// use eth_encode_packed::ethabi::ethereum_types::U256;
+    ///
+    /// let (bytes, _hash) = abi::encode_packed(&[
+    ///     SolidityDataType::Bool(true),
+    ///     SolidityDataType::Number(U256::from(42)),
+    /// ]).unwrap();
+    /// let decoded = abi::decode_packed(&[SolidityType::Bool, SolidityType::Uint(256)], &bytes).unwrap();
+    /// assert_eq!(decoded, vec![DecodedValue::Bool(true), DecodedValue::Uint(U256::from(42))]);
+    /// ```
+    pub fn decode_packed(
+        schema: &[crate::SolidityType],
+        bytes: &[u8],
+    ) -> Result<Vec<crate::DecodedValue>, crate::DecodeError> {
+        let mut offset = 0;
+        let mut out = Vec::with_capacity(schema.len());
+
+        for (index, ty) in schema.iter().enumerate() {
+            match fixed_width(ty) {
+                Some(width) => {
+                    let end = offset + width;
+                    if end > bytes.len() {
+                        return Err(crate::DecodeError::UnexpectedEndOfInput {
+                            index,
+                            needed: width,
+                            remaining: bytes.len() - offset,
+                        });
+                    }
+                    out.push(decode_fixed(ty, &bytes[offset..end]));
+                    offset = end;
+                }
+                None => {
+                    if index != schema.len() - 1 {
+                        return Err(crate::DecodeError::UnrecoverableDynamicType(index));
+                    }
+                    let chunk = &bytes[offset..];
+                    out.push(match ty {
+                        crate::SolidityType::String => crate::DecodedValue::String(
+                            String::from_utf8(chunk.to_vec())
+                                .map_err(|_| crate::DecodeError::InvalidUtf8)?,
+                        ),
+                        crate::SolidityType::Bytes => crate::DecodedValue::Bytes(chunk.to_vec()),
+                        _ => unreachable!(),
+                    });
+                    offset = bytes.len();
+                }
+            }
+        }
+
+        if offset != bytes.len() {
+            return Err(crate::DecodeError::TrailingBytes(bytes.len() - offset));
+        }
+        Ok(out)
+    }
+}
+
+/// EIP-712 typed structured data hashing (`hashStruct`/`encodeData` and the
+/// final `eth_signTypedData` signing hash), reusing [`SolidityDataType`] for
+/// leaf encoding of atomic fields.
+///
+/// See <https://eips.ethereum.org/EIPS/eip-712>.
+pub mod eip712 {
+    use std::collections::{HashMap, HashSet};
+
+    use tiny_keccak::{Hasher, Keccak};
+
+    use crate::abi;
+    use crate::ethabi::ethereum_types::{Address, U256};
+    use crate::{SolidityDataType, TakeLastXBytes};
+
+    /// One field of an EIP-712 struct type, e.g. `("wallet", "address")`.
+    #[derive(Debug, Clone)]
+    pub struct Eip712Field {
+        pub name: String,
+        pub r#type: String,
+    }
+
+    impl Eip712Field {
+        pub fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+            Self {
+                name: name.into(),
+                r#type: r#type.into(),
+            }
+        }
+    }
+
+    /// The type dictionary passed to [`hash_struct`]/[`encode_type`]: a map from
+    /// struct name to its ordered fields.
+    pub type Eip712Types = HashMap<String, Vec<Eip712Field>>;
+
+    /// A JSON-like value for an EIP-712 message or domain.
+    #[derive(Debug, Clone)]
+    pub enum Eip712Value {
+        Bool(bool),
+        Uint(U256),
+        /// The two's-complement bit pattern of a signed integer, e.g.
+        /// `U256::MAX` for `-1` (same convention as [`SolidityDataType::Int`]).
+        Int(U256),
+        Address(Address),
+        String(String),
+        Bytes(Vec<u8>),
+        Array(Vec<Eip712Value>),
+        Struct(HashMap<String, Eip712Value>),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Eip712Error {
+        /// A type name appears in a field but has no entry in the type dictionary.
+        UnknownType(String),
+        /// A struct value is missing a field declared in its type.
+        MissingField { r#type: String, field: String },
+        /// A message value's shape doesn't match its declared Solidity type.
+        TypeMismatch { r#type: String, field: String },
+    }
+
+    /// The Solidity type of an EIP-712 field, parsed from its declared type
+    /// string (e.g. `"uint256"`, `"Person"`, `"uint256[]"`, `"Person[3]"`).
+    enum FieldType<'a> {
+        Array { element: &'a str },
+        Atomic(&'a str),
+    }
+
+    fn parse_field_type(type_str: &str) -> FieldType<'_> {
+        if type_str.ends_with(']') {
+            let open = type_str.rfind('[').unwrap_or(0);
+            FieldType::Array {
+                element: &type_str[..open],
+            }
+        } else {
+            FieldType::Atomic(type_str)
+        }
+    }
+
+    fn keccak256(bytes: &[u8]) -> [u8; 32] {
+        let mut hasher = Keccak::v256();
+        hasher.update(bytes);
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        hash
+    }
+
+    fn struct_signature(types: &Eip712Types, name: &str) -> Result<String, Eip712Error> {
+        let fields = types
+            .get(name)
+            .ok_or_else(|| Eip712Error::UnknownType(name.to_string()))?;
+        let members = fields
+            .iter()
+            .map(|f| format!("{} {}", f.r#type, f.name))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(format!("{}({})", name, members))
+    }
+
+    /// Recursively collect into `visited` the struct types referenced (directly
+    /// or transitively) by `name`'s fields. Uses a `HashSet` as a visited-set so
+    /// cyclic/self-referencing types terminate; `name` itself is added too if a
+    /// cycle leads back to it, so [`encode_type`] seeds and then removes it.
+    fn collect_referenced_types(
+        types: &Eip712Types,
+        name: &str,
+        visited: &mut HashSet<String>,
+    ) -> Result<(), Eip712Error> {
+        let fields = types
+            .get(name)
+            .ok_or_else(|| Eip712Error::UnknownType(name.to_string()))?;
+        for field in fields {
+            let mut base = field.r#type.as_str();
+            while let FieldType::Array { element } = parse_field_type(base) {
+                base = element;
+            }
+            if types.contains_key(base) && visited.insert(base.to_string()) {
+                collect_referenced_types(types, base, visited)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `encodeType(primary)`: the primary type's signature followed by the
+    /// signatures of every referenced struct type, sorted alphabetically.
+    pub fn encode_type(types: &Eip712Types, primary: &str) -> Result<String, Eip712Error> {
+        let mut referenced = HashSet::new();
+        // Seed with `primary` so a direct or transitive self-reference doesn't
+        // walk back into it, then drop it before sorting - its signature is
+        // prepended separately below and must not also appear in `referenced`.
+        referenced.insert(primary.to_string());
+        collect_referenced_types(types, primary, &mut referenced)?;
+        referenced.remove(primary);
+        let mut referenced = referenced.into_iter().collect::<Vec<_>>();
+        referenced.sort();
+
+        let mut signature = struct_signature(types, primary)?;
+        for name in referenced {
+            signature.push_str(&struct_signature(types, &name)?);
+        }
+        Ok(signature)
+    }
+
+    /// `typeHash = keccak256(encodeType(primary))`.
+    pub fn type_hash(types: &Eip712Types, primary: &str) -> Result<[u8; 32], Eip712Error> {
+        Ok(keccak256(encode_type(types, primary)?.as_bytes()))
+    }
+
+    fn struct_value<'a>(
+        primary: &str,
+        field: &str,
+        value: &'a Eip712Value,
+    ) -> Result<&'a HashMap<String, Eip712Value>, Eip712Error> {
+        match value {
+            Eip712Value::Struct(map) => Ok(map),
+            _ => Err(Eip712Error::TypeMismatch {
+                r#type: primary.to_string(),
+                field: field.to_string(),
+            }),
+        }
+    }
+
+    /// Encode one atomic (non-struct, non-array) field to its 32-byte ABI word.
+    fn encode_atomic(type_str: &str, field: &str, value: &Eip712Value) -> Result<[u8; 32], Eip712Error> {
+        let mismatch = || Eip712Error::TypeMismatch {
+            r#type: type_str.to_string(),
+            field: field.to_string(),
+        };
+        let word = match type_str {
+            "bool" => match value {
+                Eip712Value::Bool(b) => abi::pad_left_32(
+                    abi::pack(&SolidityDataType::Bool(*b)).expect("Bool never fails to pack"),
+                ),
+                _ => return Err(mismatch()),
+            },
+            "address" => match value {
+                Eip712Value::Address(a) => abi::pad_left_32(
+                    abi::pack(&SolidityDataType::Address(*a)).expect("Address never fails to pack"),
+                ),
+                _ => return Err(mismatch()),
+            },
+            "string" => match value {
+                Eip712Value::String(s) => keccak256(s.as_bytes()).to_vec(),
+                _ => return Err(mismatch()),
+            },
+            "bytes" => match value {
+                Eip712Value::Bytes(b) => keccak256(b).to_vec(),
+                _ => return Err(mismatch()),
+            },
+            t if t.starts_with("uint") => match value {
+                Eip712Value::Uint(n) => {
+                    let bits: usize = t[4..].parse().unwrap_or(256);
+                    abi::pad_left_32(
+                        abi::pack(&SolidityDataType::NumberWithShift(*n, TakeLastXBytes(bits)))
+                            .expect("NumberWithShift never fails to pack"),
+                    )
+                }
+                _ => return Err(mismatch()),
+            },
+            t if t.starts_with("int") => match value {
+                Eip712Value::Int(n) => {
+                    let bits: usize = t[3..].parse().unwrap_or(256);
+                    abi::pad_left_32_sign_extend(
+                        abi::pack(&SolidityDataType::Int(*n, TakeLastXBytes(bits)))
+                            .expect("Int never fails to pack"),
+                    )
+                }
+                _ => return Err(mismatch()),
+            },
+            t if t.starts_with("bytes") => match value {
+                Eip712Value::Bytes(b) => {
+                    let width: usize = t[5..].parse().map_err(|_| mismatch())?;
+                    if b.len() != width {
+                        return Err(mismatch());
+                    }
+                    abi::pad_right_32(b.clone())
+                }
+                _ => return Err(mismatch()),
+            },
+            _ => return Err(Eip712Error::UnknownType(type_str.to_string())),
+        };
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&word[..32]);
+        Ok(out)
+    }
+
+    /// Encode one declared field of `primary` (atomic, array, or struct) to its
+    /// 32-byte ABI word, recursing into [`hash_struct`] for nested structs and
+    /// hashing the concatenated element encodings for arrays.
+    fn encode_field(
+        types: &Eip712Types,
+        primary: &str,
+        field: &Eip712Field,
+        value: &Eip712Value,
+    ) -> Result<[u8; 32], Eip712Error> {
+        match parse_field_type(&field.r#type) {
+            FieldType::Array { element } => {
+                let items = match value {
+                    Eip712Value::Array(items) => items,
+                    _ => {
+                        return Err(Eip712Error::TypeMismatch {
+                            r#type: primary.to_string(),
+                            field: field.name.clone(),
+                        })
+                    }
+                };
+                let element_field = Eip712Field::new(field.name.clone(), element);
+                let mut concatenated = Vec::new();
+                for item in items {
+                    concatenated.extend(encode_field(types, primary, &element_field, item)?);
+                }
+                Ok(keccak256(&concatenated))
+            }
+            FieldType::Atomic(t) if types.contains_key(t) => {
+                let inner = struct_value(primary, &field.name, value)?;
+                hash_struct(types, t, inner)
+            }
+            FieldType::Atomic(t) => encode_atomic(t, &field.name, value),
+        }
+    }
+
+    /// `encodeData`: `typeHash` followed by each declared field encoded to its
+    /// 32-byte ABI word, in declaration order.
+    pub fn encode_data(
+        types: &Eip712Types,
+        primary: &str,
+        value: &HashMap<String, Eip712Value>,
+    ) -> Result<Vec<u8>, Eip712Error> {
+        let fields = types
+            .get(primary)
+            .ok_or_else(|| Eip712Error::UnknownType(primary.to_string()))?;
+
+        let mut res = type_hash(types, primary)?.to_vec();
+        for field in fields {
+            let field_value = value.get(&field.name).ok_or_else(|| Eip712Error::MissingField {
+                r#type: primary.to_string(),
+                field: field.name.clone(),
+            })?;
+            res.extend(encode_field(types, primary, field, field_value)?);
+        }
+        Ok(res)
+    }
+
+    /// `hashStruct(s) = keccak256(encodeData(s))`.
+    pub fn hash_struct(
+        types: &Eip712Types,
+        primary: &str,
+        value: &HashMap<String, Eip712Value>,
+    ) -> Result<[u8; 32], Eip712Error> {
+        Ok(keccak256(&encode_data(types, primary, value)?))
+    }
+
+    /// The final EIP-712 signing hash:
+    /// `keccak256(0x1901 ‖ domainSeparator ‖ hashStruct(message))`.
+    pub fn encode(
+        domain_separator: [u8; 32],
+        types: &Eip712Types,
+        primary: &str,
+        message: &HashMap<String, Eip712Value>,
+    ) -> Result<[u8; 32], Eip712Error> {
+        let mut preimage = Vec::with_capacity(2 + 32 + 32);
+        preimage.extend([0x19, 0x01]);
+        preimage.extend(domain_separator);
+        preimage.extend(hash_struct(types, primary, message)?);
+        Ok(keccak256(&preimage))
     }
 }
 
@@ -129,7 +717,7 @@ mod tests {
             SolidityDataType::Address(Address::from(address)),
             SolidityDataType::Number(U256::from(1)),
         ];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0x000efe0000000000000000000000000000000000000000000000000000000000000fa1746869732d69732d612d73616d706c652d737472696e67d8b934580fce35a11b58c6d73adee468a2833fa80000000000000000000000000000000000000000000000000000000000000001";
         assert_eq!(hash, expected);
@@ -141,7 +729,7 @@ mod tests {
             U256::from(4001),
             TakeLastXBytes(24),
         )];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0x000fa1";
         assert_eq!(hash, expected);
@@ -150,7 +738,7 @@ mod tests {
     #[test]
     fn test_uint256() {
         let input = vec![SolidityDataType::Number(U256::from(3838110))];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0x00000000000000000000000000000000000000000000000000000000003a909e";
         assert_eq!(hash, expected);
@@ -159,7 +747,7 @@ mod tests {
     #[test]
     fn test_string() {
         let input = vec![SolidityDataType::String("this-is-a-sample-string")];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0x746869732d69732d612d73616d706c652d737472696e67";
         assert_eq!(hash, expected);
@@ -170,7 +758,7 @@ mod tests {
         let address = hex::decode("d8b934580fcE35a11B58C6D73aDeE468a2833fa8").unwrap();
         let address: [u8; 20] = address.try_into().unwrap();
         let input = vec![SolidityDataType::Address(Address::from(address))];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0xd8b934580fce35a11b58c6d73adee468a2833fa8";
         assert_eq!(hash, expected);
@@ -179,7 +767,7 @@ mod tests {
     #[test]
     fn test_bool() {
         let input = vec![SolidityDataType::Bool(false)];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0x00";
         assert_eq!(hash, expected);
@@ -191,9 +779,372 @@ mod tests {
         let bytes: [u8; 30] = bytes.try_into().unwrap();
 
         let input = vec![SolidityDataType::Bytes(&bytes)];
-        let (_bytes, hash) = abi::encode_packed(&input);
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
         let hash = format!("0x{:}", hash);
         let expected = "0xabababababababababababababababababababababababababababababab";
         assert_eq!(hash, expected);
     }
+
+    #[test]
+    fn test_int8_negative_one() {
+        // int8(-1) == 0xff
+        let input = vec![SolidityDataType::Int(U256::MAX, TakeLastXBytes(8))];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0xff";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_int24_negative_two() {
+        // int24(-2) == 0xfffffe
+        let input = vec![SolidityDataType::Int(
+            U256::MAX - U256::from(1),
+            TakeLastXBytes(24),
+        )];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0xfffffe";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_int_positive() {
+        // int256(1) packs the same as a positive Number
+        let input = vec![SolidityDataType::Int(U256::from(1), TakeLastXBytes(256))];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_array_of_numbers() {
+        // uint256[] [1, 2] -> each element left-padded to 32 bytes
+        let input = vec![SolidityDataType::Array(vec![
+            SolidityDataType::Number(U256::from(1)),
+            SolidityDataType::Number(U256::from(2)),
+        ])];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0x\
+            0000000000000000000000000000000000000000000000000000000000000001\
+            0000000000000000000000000000000000000000000000000000000000000002";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_array_of_bools_and_addresses() {
+        let address = hex::decode("d8b934580fcE35a11B58C6D73aDeE468a2833fa8").unwrap();
+        let address: [u8; 20] = address.try_into().unwrap();
+        let input = vec![SolidityDataType::Array(vec![
+            SolidityDataType::Bool(true),
+            SolidityDataType::Address(Address::from(address)),
+        ])];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0x\
+            0000000000000000000000000000000000000000000000000000000000000001\
+            000000000000000000000000d8b934580fce35a11b58c6d73adee468a2833fa8";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_array_of_strings_right_padded() {
+        let input = vec![SolidityDataType::Array(vec![SolidityDataType::String(
+            "hi",
+        )])];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0x6869000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_array_of_negative_int_is_sign_extended() {
+        // int8(-1) inside an array is sign-extended (0xff-filled), not
+        // zero-padded, so it reads back as 32 bytes of 0xff.
+        let input = vec![SolidityDataType::Array(vec![SolidityDataType::Int(
+            U256::MAX,
+            TakeLastXBytes(8),
+        )])];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_encode_packed_keccak() {
+        let input = vec![SolidityDataType::String("hello world")];
+        let (_bytes, hash) = abi::encode_packed_keccak(&input).unwrap();
+        let hash = format!("0x{:}", hex::encode(hash));
+        let expected = "0x47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad";
+        assert_eq!(hash, expected);
+    }
+
+    // Types/message mirror the canonical "Mail" example from the EIP-712 spec
+    // (https://eips.ethereum.org/EIPS/eip-712), with stand-in addresses.
+    fn mail_types() -> eip712::Eip712Types {
+        let mut types = std::collections::HashMap::new();
+        types.insert(
+            "Person".to_string(),
+            vec![
+                eip712::Eip712Field::new("name", "string"),
+                eip712::Eip712Field::new("wallet", "address"),
+            ],
+        );
+        types.insert(
+            "Mail".to_string(),
+            vec![
+                eip712::Eip712Field::new("from", "Person"),
+                eip712::Eip712Field::new("to", "Person"),
+                eip712::Eip712Field::new("contents", "string"),
+            ],
+        );
+        types
+    }
+
+    fn mail_message() -> std::collections::HashMap<String, eip712::Eip712Value> {
+        let addr_a = hex::decode("d8b934580fcE35a11B58C6D73aDeE468a2833fa8").unwrap();
+        let addr_a: [u8; 20] = addr_a.try_into().unwrap();
+        let addr_b = hex::decode("1111111111111111111111111111111111111111").unwrap();
+        let addr_b: [u8; 20] = addr_b.try_into().unwrap();
+
+        let mut from = std::collections::HashMap::new();
+        from.insert(
+            "name".to_string(),
+            eip712::Eip712Value::String("Cow".to_string()),
+        );
+        from.insert(
+            "wallet".to_string(),
+            eip712::Eip712Value::Address(Address::from(addr_a)),
+        );
+
+        let mut to = std::collections::HashMap::new();
+        to.insert(
+            "name".to_string(),
+            eip712::Eip712Value::String("Bob".to_string()),
+        );
+        to.insert(
+            "wallet".to_string(),
+            eip712::Eip712Value::Address(Address::from(addr_b)),
+        );
+
+        let mut message = std::collections::HashMap::new();
+        message.insert("from".to_string(), eip712::Eip712Value::Struct(from));
+        message.insert("to".to_string(), eip712::Eip712Value::Struct(to));
+        message.insert(
+            "contents".to_string(),
+            eip712::Eip712Value::String("Hello, Bob!".to_string()),
+        );
+        message
+    }
+
+    #[test]
+    fn test_eip712_encode_type() {
+        let types = mail_types();
+        let signature = eip712::encode_type(&types, "Mail").unwrap();
+        assert_eq!(
+            signature,
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn test_eip712_type_hash() {
+        let types = mail_types();
+        let hash = eip712::type_hash(&types, "Mail").unwrap();
+        let hash = format!("0x{}", hex::encode(hash));
+        let expected = "0xa0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_eip712_hash_struct() {
+        let types = mail_types();
+        let message = mail_message();
+        let hash = eip712::hash_struct(&types, "Mail", &message).unwrap();
+        let hash = format!("0x{}", hex::encode(hash));
+        let expected = "0x8affafc682602a4290d9fea6a980964684fb3245cbd025aa6dcf38af56d20134";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_eip712_encode_signing_hash() {
+        let types = mail_types();
+        let message = mail_message();
+        let domain_separator = [0x11u8; 32];
+        let hash = eip712::encode(domain_separator, &types, "Mail", &message).unwrap();
+        let hash = format!("0x{}", hex::encode(hash));
+        let expected = "0x83f4f070bd2fd4965fd5a63839df439dbea3d50b616e5cf1487ca9b623e36575";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_eip712_unknown_type_errors() {
+        let types = mail_types();
+        let err = eip712::encode_type(&types, "Nonexistent").unwrap_err();
+        assert_eq!(err, eip712::Eip712Error::UnknownType("Nonexistent".to_string()));
+    }
+
+    #[test]
+    fn test_eip712_encode_type_excludes_self_referencing_primary() {
+        // Node { value: uint256, next: Node } - a linked-list-style direct
+        // self-reference. The primary type's own signature must appear only
+        // once, not once up front and again in the referenced-types tail.
+        let mut types = std::collections::HashMap::new();
+        types.insert(
+            "Node".to_string(),
+            vec![
+                eip712::Eip712Field::new("value", "uint256"),
+                eip712::Eip712Field::new("next", "Node"),
+            ],
+        );
+        let signature = eip712::encode_type(&types, "Node").unwrap();
+        assert_eq!(signature, "Node(uint256 value,Node next)");
+    }
+
+    #[test]
+    fn test_eip712_encode_type_excludes_primary_in_mutual_cycle() {
+        // A { b: B } / B { a: A } - a cycle through another struct. The
+        // primary type must still be excluded from the referenced tail.
+        let mut types = std::collections::HashMap::new();
+        types.insert(
+            "A".to_string(),
+            vec![eip712::Eip712Field::new("b", "B")],
+        );
+        types.insert(
+            "B".to_string(),
+            vec![eip712::Eip712Field::new("a", "A")],
+        );
+        let signature = eip712::encode_type(&types, "A").unwrap();
+        assert_eq!(signature, "A(B b)B(A a)");
+    }
+
+    #[test]
+    fn test_eip712_missing_field_errors() {
+        let types = mail_types();
+        let mut message = mail_message();
+        message.remove("contents");
+        let err = eip712::hash_struct(&types, "Mail", &message).unwrap_err();
+        assert_eq!(
+            err,
+            eip712::Eip712Error::MissingField {
+                r#type: "Mail".to_string(),
+                field: "contents".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_packed_round_trip() {
+        let address = hex::decode("d8b934580fcE35a11B58C6D73aDeE468a2833fa8").unwrap();
+        let address: [u8; 20] = address.try_into().unwrap();
+        let input = vec![
+            SolidityDataType::Address(Address::from(address)),
+            SolidityDataType::Bool(true),
+            SolidityDataType::NumberWithShift(U256::from(3838), TakeLastXBytes(24)),
+            SolidityDataType::Number(U256::from(42)),
+            SolidityDataType::String("tail"),
+        ];
+        let (bytes, _hash) = abi::encode_packed(&input).unwrap();
+
+        let schema = [
+            SolidityType::Address,
+            SolidityType::Bool,
+            SolidityType::Uint(24),
+            SolidityType::Uint(256),
+            SolidityType::String,
+        ];
+        let decoded = abi::decode_packed(&schema, &bytes).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                DecodedValue::Address(Address::from(address)),
+                DecodedValue::Bool(true),
+                DecodedValue::Uint(U256::from(3838)),
+                DecodedValue::Uint(U256::from(42)),
+                DecodedValue::String("tail".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_packed_round_trip_negative_int() {
+        // int24(-2) packs as 0xfffffe and must decode back to the
+        // two's-complement bit pattern `U256::MAX - 1`, not a zero-extended
+        // `0x0000fffffe`.
+        let input = vec![SolidityDataType::Int(
+            U256::MAX - U256::from(1),
+            TakeLastXBytes(24),
+        )];
+        let (bytes, _hash) = abi::encode_packed(&input).unwrap();
+
+        let schema = [SolidityType::Int(24)];
+        let decoded = abi::decode_packed(&schema, &bytes).unwrap();
+        assert_eq!(decoded, vec![DecodedValue::Int(U256::MAX - U256::from(1))]);
+    }
+
+    #[test]
+    fn test_decode_packed_dynamic_type_must_be_last() {
+        let schema = [SolidityType::String, SolidityType::Bool];
+        let err = abi::decode_packed(&schema, b"abc\x01").unwrap_err();
+        assert_eq!(err, DecodeError::UnrecoverableDynamicType(0));
+    }
+
+    #[test]
+    fn test_decode_packed_unexpected_end_of_input() {
+        let schema = [SolidityType::Uint(256)];
+        let err = abi::decode_packed(&schema, &[0u8; 10]).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::UnexpectedEndOfInput {
+                index: 0,
+                needed: 32,
+                remaining: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_packed_trailing_bytes() {
+        let schema = [SolidityType::Bool];
+        let err = abi::decode_packed(&schema, &[1, 2, 3]).unwrap_err();
+        assert_eq!(err, DecodeError::TrailingBytes(2));
+    }
+
+    #[test]
+    fn test_fixed_bytes_top_level() {
+        let input = vec![SolidityDataType::FixedBytes(&[0xde, 0xad, 0xbe, 0xef], 4)];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0xdeadbeef";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_fixed_bytes_in_array_is_right_padded() {
+        let input = vec![SolidityDataType::Array(vec![SolidityDataType::FixedBytes(
+            &[0xde, 0xad, 0xbe, 0xef],
+            4,
+        )])];
+        let (_bytes, hash) = abi::encode_packed(&input).unwrap();
+        let hash = format!("0x{:}", hash);
+        let expected = "0xdeadbeef00000000000000000000000000000000000000000000000000000000";
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_fixed_bytes_length_mismatch_errors() {
+        let input = vec![SolidityDataType::FixedBytes(&[0xde, 0xad, 0xbe], 4)];
+        let err = abi::encode_packed(&input).unwrap_err();
+        assert_eq!(
+            err,
+            EncodeError::FixedBytesLengthMismatch {
+                declared: 4,
+                actual: 3,
+            }
+        );
+    }
 }